@@ -1,22 +1,172 @@
-use clap::{value_parser, Arg, Command, ValueEnum};
+use clap::{value_parser, Arg, ArgAction, Command, ValueEnum};
 use colored::*;
 use std::collections::HashSet;
-use sysinfo::Disks;
+use std::ffi::CString;
+use std::fs;
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
 
 const MAX_CHARS: usize = 50;
 
+// File system types that are commonly backed by a network mount, and can
+// therefore hang indefinitely if the remote end is unreachable.
+const NETWORK_FS_TYPES: &[&str] = &[
+    "nfs", "nfs2", "nfs3", "nfs4", "cifs", "smb", "smbfs", "smb2", "smb3", "fuse.sshfs", "sshfs",
+    "9p", "afs",
+];
+
+fn is_network_fs(fs_type: &str) -> bool {
+    let fs_type = fs_type.to_lowercase();
+    NETWORK_FS_TYPES.contains(&fs_type.as_str())
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
 enum OutputMode {
     Normal,
     Compact,
     Table,
+    Json,
 }
 
-fn get_frac(avail: u64, total: u64) -> f64 {
-    if total == 0 {
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum SortKey {
+    Mount,
+    Size,
+    Free,
+    Usage,
+    Name,
+}
+
+// Percentage against used+avail rather than raw total: reserved blocks
+// (e.g. ext4's root-reserved 5%) are in total but in neither used nor
+// avail, so `1 - avail/total` would count them as used space.
+fn get_frac(used: u64, avail: u64) -> f64 {
+    let denom = used + avail;
+    if denom == 0 {
         return 0.0;
     }
-    1.0 - (avail as f64 / total as f64)
+    used as f64 / denom as f64
+}
+
+fn statvfs_raw(mount_point: &Path) -> Option<libc::statvfs> {
+    let c_path = CString::new(mount_point.as_os_str().as_bytes()).ok()?;
+    unsafe {
+        let mut stat: libc::statvfs = std::mem::zeroed();
+        if libc::statvfs(c_path.as_ptr(), &mut stat) == 0 {
+            Some(stat)
+        } else {
+            None
+        }
+    }
+}
+
+// Runs statvfs(2) on a worker thread and waits up to `timeout`. A stalled
+// NFS/CIFS mount blocks the syscall itself, so the only way to bound it is
+// to give up waiting on the result rather than trying to cancel the call;
+// the worker thread is leaked if it never returns. This is the query site
+// that populates size/free for a network mount, so bounding it here (and
+// calling it before we ever look at a mount's numbers) is what keeps a
+// stalled remote share from hanging the whole run.
+fn statvfs_within(mount_point: &Path, timeout: Duration) -> Option<libc::statvfs> {
+    let mount_point = mount_point.to_path_buf();
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = tx.send(statvfs_raw(&mount_point));
+    });
+    rx.recv_timeout(timeout).ok().flatten()
+}
+
+struct MountEntry {
+    device: String,
+    mount_point: PathBuf,
+    fs_type: String,
+    read_only: bool,
+}
+
+// Pseudo filesystems that carry no disk space of their own (procfs, sysfs,
+// cgroup hierarchies, devtmpfs/devpts, ...). `sysinfo::Disks` filtered these
+// out for us; now that we read `/proc/mounts` directly we have to do it
+// ourselves, or every container/cgroup mount shows up as a bogus 0B/0% row.
+const PSEUDO_FS_TYPES: &[&str] = &[
+    "proc",
+    "sysfs",
+    "cgroup",
+    "cgroup2",
+    "devpts",
+    "devtmpfs",
+    "tmpfs",
+    "securityfs",
+    "pstore",
+    "bpf",
+    "debugfs",
+    "tracefs",
+    "configfs",
+    "fusectl",
+    "mqueue",
+    "hugetlbfs",
+    "autofs",
+    "binfmt_misc",
+    "rpc_pipefs",
+    "overlay",
+];
+
+fn is_pseudo_fs(fs_type: &str) -> bool {
+    PSEUDO_FS_TYPES.contains(&fs_type)
+}
+
+// We enumerate mounts ourselves instead of going through
+// `sysinfo::Disks::new_with_refreshed_list()`, which calls statvfs(2) on
+// every mount (including unreachable network ones) synchronously while
+// building its list -- before `--no-remote`/`--timeout` ever get a say.
+// We still have to reproduce the curation `sysinfo::Disks` used to do for
+// us: drop pseudo filesystems and collapse repeated entries down to one
+// row per (device, mount_point). A device bind-mounted at several mount
+// points still gets one row per mount point.
+fn read_mounts() -> Vec<MountEntry> {
+    let mut seen = HashSet::new();
+    fs::read_to_string("/proc/mounts")
+        .unwrap_or_default()
+        .lines()
+        .filter_map(parse_mount_line)
+        .filter(|entry| !is_pseudo_fs(&entry.fs_type))
+        .filter(|entry| seen.insert((entry.device.clone(), entry.mount_point.clone())))
+        .collect()
+}
+
+// /proc/mounts escapes space/tab/newline/backslash in fields as \NNN octal.
+fn unescape_mount_field(field: &str) -> String {
+    let bytes = field.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\\' && i + 3 < bytes.len() {
+            if let Ok(code) = u8::from_str_radix(&field[i + 1..i + 4], 8) {
+                out.push(code);
+                i += 4;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn parse_mount_line(line: &str) -> Option<MountEntry> {
+    let mut fields = line.split_whitespace();
+    let device = fields.next()?.to_string();
+    let mount_point = unescape_mount_field(fields.next()?);
+    let fs_type = fields.next()?.to_string();
+    let options = fields.next()?;
+    Some(MountEntry {
+        device,
+        mount_point: PathBuf::from(mount_point),
+        fs_type,
+        read_only: options.split(',').any(|opt| opt == "ro"),
+    })
 }
 
 struct NDFDisk {
@@ -25,22 +175,150 @@ struct NDFDisk {
     mnt: String,
     size: u64,
     free: u64,
+    used: u64,
+    reachable: bool,
+    fs_type: String,
+    read_only: bool,
+    inodes_total: Option<u64>,
+    inodes_free: Option<u64>,
+    inode_frac: Option<f64>,
+}
+
+// Raw-value view of an `NDFDisk` for `OutputMode::Json`: byte counts stay
+// as integers (not `format_size` strings) so downstream tooling (jq,
+// status bars) can do its own math.
+#[derive(serde::Serialize)]
+struct DiskRecord<'a> {
+    name: &'a str,
+    mount: &'a str,
+    size: u64,
+    free: u64,
+    used: u64,
+    usage_frac: f64,
+    fs_type: &'a str,
+    read_only: bool,
 }
 
 impl NDFDisk {
-    fn create_ndf_disk(disk: &sysinfo::Disk) -> NDFDisk {
-        let frac = get_frac(disk.available_space(), disk.total_space());
+    // `stat` is the result of the (possibly timeout-bounded) statvfs(2)
+    // call for `entry.mount_point`, already resolved by the caller -- this
+    // is the only place size/free/inode numbers come from now.
+    fn create_ndf_disk(entry: &MountEntry, stat: Option<libc::statvfs>, with_inodes: bool) -> NDFDisk {
+        let reachable = stat.is_some();
+        // Both the byte and inode numbers come out of this single
+        // statvfs(2) result: calling statvfs a second time for inodes would
+        // reopen the exact hang this command is bounding above.
+        let (total, avail, used, inodes_total, inodes_free, inode_frac) = match stat {
+            Some(s) => {
+                let frsize = s.f_frsize;
+                let total = s.f_blocks * frsize;
+                let avail = s.f_bavail * frsize;
+                // `used` comes from f_bfree, not `total - avail`: f_bavail
+                // excludes blocks reserved for root, so deriving used from
+                // it would count that reservation as used space.
+                let used = s.f_blocks.saturating_sub(s.f_bfree) * frsize;
+                let (itotal, ifree, ifrac) = if with_inodes {
+                    let itotal = s.f_files;
+                    let ifree = s.f_ffree;
+                    let iused = itotal.saturating_sub(ifree);
+                    (Some(itotal), Some(ifree), Some(get_frac(iused, ifree)))
+                } else {
+                    (None, None, None)
+                };
+                (total, avail, used, itotal, ifree, ifrac)
+            }
+            None => (0, 0, 0, None, None, None),
+        };
+        let frac = get_frac(used, avail);
         NDFDisk {
-            name: disk.name().to_string_lossy().to_string(),
+            name: entry.device.clone(),
             space_as_frac: frac,
-            mnt: disk.mount_point().to_string_lossy().to_string(),
-            size: disk.total_space(),
-            free: disk.available_space(),
+            mnt: entry.mount_point.to_string_lossy().to_string(),
+            size: total,
+            free: avail,
+            used,
+            reachable,
+            fs_type: entry.fs_type.clone(),
+            read_only: entry.read_only,
+            inodes_total,
+            inodes_free,
+            inode_frac,
+        }
+    }
+
+    fn ro_marker(&self) -> &'static str {
+        if self.read_only {
+            " 🔒"
+        } else {
+            ""
         }
     }
 
-    fn create_bar(&self) -> ColoredString {
-        let chars_num = (MAX_CHARS as f64 * self.space_as_frac).ceil() as usize;
+    fn to_record(&self) -> DiskRecord<'_> {
+        DiskRecord {
+            name: &self.name,
+            mount: &self.mnt,
+            size: self.size,
+            free: self.free,
+            used: self.used,
+            usage_frac: self.space_as_frac,
+            fs_type: &self.fs_type,
+            read_only: self.read_only,
+        }
+    }
+
+    // The fraction actually rendered by the bar/percentage: byte usage
+    // normally, inode usage when `--inodes` is active.
+    fn display_frac(&self, inodes_mode: bool) -> f64 {
+        if inodes_mode {
+            self.inode_frac.unwrap_or(0.0)
+        } else {
+            self.space_as_frac
+        }
+    }
+
+    fn size_display(&self, inodes_mode: bool) -> String {
+        if !self.reachable {
+            return "?".to_string();
+        }
+        if inodes_mode {
+            self.inodes_total
+                .map(format_count)
+                .unwrap_or_else(|| "?".to_string())
+        } else {
+            format_size(self.size)
+        }
+    }
+
+    fn free_display(&self, inodes_mode: bool) -> String {
+        if !self.reachable {
+            return "?".to_string();
+        }
+        if inodes_mode {
+            self.inodes_free
+                .map(format_count)
+                .unwrap_or_else(|| "?".to_string())
+        } else {
+            format_size(self.free)
+        }
+    }
+
+    fn used_display(&self, inodes_mode: bool) -> String {
+        if !self.reachable {
+            return "?".to_string();
+        }
+        if inodes_mode {
+            match (self.inodes_total, self.inodes_free) {
+                (Some(total), Some(free)) => format_count(total.saturating_sub(free)),
+                _ => "?".to_string(),
+            }
+        } else {
+            format_size(self.used)
+        }
+    }
+
+    fn create_bar(&self, frac: f64) -> ColoredString {
+        let chars_num = (MAX_CHARS as f64 * frac).ceil() as usize;
         let chars = "▓".repeat(chars_num);
         let rem_num = MAX_CHARS - chars_num;
         let rem = "░".repeat(rem_num);
@@ -52,16 +330,16 @@ impl NDFDisk {
         }
     }
 
-    fn create_plain_bar(&self) -> String {
-        let chars_num = (MAX_CHARS as f64 * self.space_as_frac).ceil() as usize;
+    fn create_plain_bar(&self, frac: f64) -> String {
+        let chars_num = (MAX_CHARS as f64 * frac).ceil() as usize;
         let chars = "▓".repeat(chars_num);
         let rem_num = MAX_CHARS - chars_num;
         let rem = "░".repeat(rem_num);
         format!("{}{}", chars, rem)
     }
 
-    fn is_high_usage(&self) -> bool {
-        let rem_num = MAX_CHARS - (MAX_CHARS as f64 * self.space_as_frac).ceil() as usize;
+    fn is_high_usage(&self, frac: f64) -> bool {
+        let rem_num = MAX_CHARS - (MAX_CHARS as f64 * frac).ceil() as usize;
         rem_num < (MAX_CHARS as f64 * 0.2) as usize
     }
 }
@@ -80,6 +358,10 @@ fn format_size(size: u64) -> String {
     }
 }
 
+fn format_count(count: u64) -> String {
+    count.to_string()
+}
+
 fn main() {
     let matches = Command::new("ndf")
         .about("Nice disk free.")
@@ -87,7 +369,7 @@ fn main() {
             Arg::new("mode")
                 .value_parser(value_parser!(OutputMode))
                 .default_value("table")
-                .help("Display mode: normal | compact | table"),
+                .help("Display mode: normal | compact | table | json"),
         )
         .arg(
             Arg::new("only-mp")
@@ -101,9 +383,66 @@ fn main() {
                 .value_name("MOUNTPOINTS")
                 .help("Exclude specified mount points, comma separated"),
         )
+        .arg(
+            Arg::new("only-type")
+                .long("only-type")
+                .value_name("TYPES")
+                .help("Show only specified filesystem types, comma separated"),
+        )
+        .arg(
+            Arg::new("exclude-type")
+                .long("exclude-type")
+                .value_name("TYPES")
+                .help("Exclude specified filesystem types, comma separated"),
+        )
+        .arg(
+            Arg::new("inodes")
+                .long("inodes")
+                .action(ArgAction::SetTrue)
+                .help("Report inode usage (statvfs) instead of byte usage"),
+        )
+        .arg(
+            Arg::new("sort")
+                .long("sort")
+                .value_parser(value_parser!(SortKey))
+                .default_value("usage")
+                .help("Sort disks by: mount | size | free | usage | name"),
+        )
+        .arg(
+            Arg::new("reverse")
+                .long("reverse")
+                .action(ArgAction::SetTrue)
+                .help("Reverse the sort order"),
+        )
+        .arg(
+            Arg::new("no-remote")
+                .long("no-remote")
+                .action(ArgAction::SetTrue)
+                .help("Exclude network filesystems (nfs, cifs, sshfs, ...)"),
+        )
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .value_name("MS")
+                .value_parser(value_parser!(u64))
+                .default_value("1000")
+                .help("Max time in milliseconds to wait on a network filesystem before showing it as unreachable"),
+        )
+        .arg(
+            Arg::new("rw-only")
+                .long("rw-only")
+                .action(ArgAction::SetTrue)
+                .help("Hide read-only filesystems"),
+        )
         .get_matches();
 
     let output_mode = *matches.get_one::<OutputMode>("mode").unwrap();
+    let inodes_mode = matches.get_flag("inodes");
+    let sort_key = *matches.get_one::<SortKey>("sort").unwrap();
+    let reverse = matches.get_flag("reverse");
+    let no_remote = matches.get_flag("no-remote");
+    let timeout = Duration::from_millis(*matches.get_one::<u64>("timeout").unwrap());
+    let rw_only = matches.get_flag("rw-only");
 
     let only_mp: Option<HashSet<_>> = matches
         .get_one::<String>("only-mp")
@@ -113,36 +452,97 @@ fn main() {
         .get_one::<String>("exclude-mp")
         .map(|s| s.split(',').map(|s| s.trim().to_string()).collect());
 
+    let only_type: Option<HashSet<_>> = matches
+        .get_one::<String>("only-type")
+        .map(|s| s.split(',').map(|s| s.trim().to_string()).collect());
+
+    let exclude_type: Option<HashSet<_>> = matches
+        .get_one::<String>("exclude-type")
+        .map(|s| s.split(',').map(|s| s.trim().to_string()).collect());
+
     let mut disks: Vec<NDFDisk> = Vec::new();
-    for disk in Disks::new_with_refreshed_list().list() {
-        let mnt = disk.mount_point().to_string_lossy();
-        // ignore overlay and snap mounts
-        if disk.file_system() == "overlay" || mnt.starts_with("/var/snap/") {
+    for entry in read_mounts() {
+        // overlay mounts are filtered in read_mounts(); snap's private
+        // mount namespace still needs excluding by path here.
+        if entry.mount_point.starts_with("/var/snap/") {
             continue;
         }
+        let is_remote = is_network_fs(&entry.fs_type);
+        if no_remote && is_remote {
+            continue;
+        }
+        let mnt = entry.mount_point.to_string_lossy().to_string();
         if let Some(ref only) = only_mp {
-            if !only.contains(mnt.as_ref()) {
+            if !only.contains(&mnt) {
                 continue;
             }
         }
         if let Some(ref exclude) = exclude_mp {
-            if exclude.contains(mnt.as_ref()) {
+            if exclude.contains(&mnt) {
+                continue;
+            }
+        }
+        if let Some(ref only) = only_type {
+            if !only.contains(&entry.fs_type) {
                 continue;
             }
         }
-        disks.push(NDFDisk::create_ndf_disk(disk));
+        if let Some(ref exclude) = exclude_type {
+            if exclude.contains(&entry.fs_type) {
+                continue;
+            }
+        }
+        if rw_only && entry.read_only {
+            continue;
+        }
+        // Bound the statvfs(2) call itself for network mounts -- this is
+        // the call that can hang, not just our handling of its result.
+        let stat = if is_remote {
+            statvfs_within(&entry.mount_point, timeout)
+        } else {
+            statvfs_raw(&entry.mount_point)
+        };
+        disks.push(NDFDisk::create_ndf_disk(&entry, stat, inodes_mode));
+    }
+
+    disks.sort_by(|a, b| match sort_key {
+        SortKey::Mount => a.mnt.cmp(&b.mnt),
+        SortKey::Name => a.name.cmp(&b.name),
+        SortKey::Size => a.size.cmp(&b.size),
+        SortKey::Free => a.free.cmp(&b.free),
+        SortKey::Usage => a
+            .space_as_frac
+            .partial_cmp(&b.space_as_frac)
+            .unwrap_or(std::cmp::Ordering::Equal),
+    });
+    // usage defaults to descending (fullest disks first); --reverse flips
+    // whatever the default for the chosen key is.
+    let descending_by_default = sort_key == SortKey::Usage;
+    if descending_by_default != reverse {
+        disks.reverse();
     }
 
-    println!("{}", "ndf - nice disk free".bold());
+    if output_mode == OutputMode::Json {
+        colored::control::set_override(false);
+    } else {
+        println!("{}", "ndf - nice disk free".bold());
+    }
 
     match output_mode {
+        OutputMode::Json => {
+            let records: Vec<DiskRecord> = disks.iter().map(NDFDisk::to_record).collect();
+            println!("{}", serde_json::to_string_pretty(&records).unwrap());
+        }
         OutputMode::Compact => {
             for disk in disks {
+                let frac = disk.display_frac(inodes_mode);
                 println!(
-                    "{}: {} {:.0}%",
+                    "{}{}: {} {} {:.0}%",
                     disk.name,
-                    disk.create_bar(),
-                    disk.space_as_frac * 100.0
+                    disk.ro_marker(),
+                    disk.create_bar(frac),
+                    disk.used_display(inodes_mode),
+                    frac * 100.0
                 );
             }
         }
@@ -152,44 +552,55 @@ fn main() {
             let mut max_size_len = "Size".len();
             let mut max_free_len = "Free".len();
             let mut max_name_len = "Name".len();
+            let mut max_used_len = 0usize;
+            let mut max_type_len = "Type".len();
 
             for disk in &disks {
                 max_mount_len = max_mount_len.max(disk.mnt.len().min(20));
-                max_size_len = max_size_len.max(format_size(disk.size).len());
-                max_free_len = max_free_len.max(format_size(disk.free).len());
+                max_size_len = max_size_len.max(disk.size_display(inodes_mode).len());
+                max_free_len = max_free_len.max(disk.free_display(inodes_mode).len());
                 max_name_len = max_name_len.max(disk.name.len().min(15));
+                max_used_len = max_used_len.max(disk.used_display(inodes_mode).len());
+                max_type_len = max_type_len.max(disk.fs_type.len());
             }
 
-            // Usage列固定为进度条宽度 + 百分比
-            let usage_len = MAX_CHARS + 4; // 50字符进度条 + 空格 + 3字符百分比
+            // Usage列固定为进度条宽度 + 已用量 + 百分比
+            let usage_len = MAX_CHARS + 2 + max_used_len + 3; // 50字符进度条 + 空格 + 已用量 + 空格 + 3字符百分比
+            let ro_len = "RO".len();
 
             // 手动创建表格
             println!(
-                "┌{:─<width_mount$}┬{:─<width_size$}┬{:─<width_free$}┬{:─<width_usage$}┬{:─<width_name$}┐",
-                "", "", "", "", "",
+                "┌{:─<width_mount$}┬{:─<width_size$}┬{:─<width_free$}┬{:─<width_usage$}┬{:─<width_name$}┬{:─<width_type$}┬{:─<width_ro$}┐",
+                "", "", "", "", "", "", "",
                 width_mount = max_mount_len + 2,
                 width_size = max_size_len + 2,
                 width_free = max_free_len + 2,
                 width_usage = usage_len + 2,
-                width_name = max_name_len + 2
+                width_name = max_name_len + 2,
+                width_type = max_type_len + 2,
+                width_ro = ro_len + 2
             );
             println!(
-                "│ {:<width_mount$} │ {:>width_size$} │ {:>width_free$} │ {:^width_usage$} │ {:<width_name$} │",
-                "Mount", "Size", "Free", "Usage", "Name",
+                "│ {:<width_mount$} │ {:>width_size$} │ {:>width_free$} │ {:^width_usage$} │ {:<width_name$} │ {:<width_type$} │ {:<width_ro$} │",
+                "Mount", "Size", "Free", "Usage", "Name", "Type", "RO",
                 width_mount = max_mount_len,
                 width_size = max_size_len,
                 width_free = max_free_len,
                 width_usage = usage_len,
-                width_name = max_name_len
+                width_name = max_name_len,
+                width_type = max_type_len,
+                width_ro = ro_len
             );
             println!(
-                "├{:─<width_mount$}┼{:─<width_size$}┼{:─<width_free$}┼{:─<width_usage$}┼{:─<width_name$}┤",
-                "", "", "", "", "",
+                "├{:─<width_mount$}┼{:─<width_size$}┼{:─<width_free$}┼{:─<width_usage$}┼{:─<width_name$}┼{:─<width_type$}┼{:─<width_ro$}┤",
+                "", "", "", "", "", "", "",
                 width_mount = max_mount_len + 2,
                 width_size = max_size_len + 2,
                 width_free = max_free_len + 2,
                 width_usage = usage_len + 2,
-                width_name = max_name_len + 2
+                width_name = max_name_len + 2,
+                width_type = max_type_len + 2,
+                width_ro = ro_len + 2
             );
 
             for disk in disks {
@@ -198,8 +609,8 @@ fn main() {
                 } else {
                     disk.mnt.clone()
                 };
-                let size_text = format_size(disk.size);
-                let free_text = format_size(disk.free);
+                let size_text = disk.size_display(inodes_mode);
+                let free_text = disk.free_display(inodes_mode);
                 let name_text = if disk.name.len() > 15 {
                     disk.name[..12].to_string() + "..."
                 } else {
@@ -207,43 +618,62 @@ fn main() {
                 };
 
                 // 构建Usage列内容
-                let plain_bar = disk.create_plain_bar();
-                let percentage = format!("{:.0}%", disk.space_as_frac * 100.0);
+                let frac = disk.display_frac(inodes_mode);
+                let plain_bar = disk.create_plain_bar(frac);
+                let used_text = disk.used_display(inodes_mode);
+                let percentage = format!("{:.0}%", frac * 100.0);
 
-                let colored_bar = if disk.is_high_usage() {
+                let colored_bar = if disk.is_high_usage(frac) {
                     plain_bar.red()
                 } else {
                     plain_bar.green()
                 };
 
+                // `🔒` renders as a double-width terminal glyph but counts
+                // as a single `char`, so `{:<width_ro$}` pads it one column
+                // short of the border. Pad it ourselves against `ro_len`'s
+                // fixed 2-column budget instead of relying on char-count
+                // padding.
+                let ro_text = if disk.read_only {
+                    "🔒".to_string() + &" ".repeat(ro_len.saturating_sub(2))
+                } else {
+                    " ".repeat(ro_len)
+                };
+
                 println!(
-                    "│ {:<width_mount$} │ {:>width_size$} │ {:>width_free$} │ {} {:>3} │ {:<width_name$} │",
-                    mount_text, size_text, free_text, colored_bar, percentage, name_text,
+                    "│ {:<width_mount$} │ {:>width_size$} │ {:>width_free$} │ {} {:>width_used$} {:>3} │ {:<width_name$} │ {:<width_type$} │ {} │",
+                    mount_text, size_text, free_text, colored_bar, used_text, percentage, name_text, disk.fs_type, ro_text,
                     width_mount = max_mount_len,
                     width_size = max_size_len,
                     width_free = max_free_len,
-                    width_name = max_name_len
+                    width_used = max_used_len,
+                    width_name = max_name_len,
+                    width_type = max_type_len,
                 );
             }
 
             println!(
-                "└{:─<width_mount$}┴{:─<width_size$}┴{:─<width_free$}┴{:─<width_usage$}┴{:─<width_name$}┘",
-                "", "", "", "", "",
+                "└{:─<width_mount$}┴{:─<width_size$}┴{:─<width_free$}┴{:─<width_usage$}┴{:─<width_name$}┴{:─<width_type$}┴{:─<width_ro$}┘",
+                "", "", "", "", "", "", "",
                 width_mount = max_mount_len + 2,
                 width_size = max_size_len + 2,
                 width_free = max_free_len + 2,
                 width_usage = usage_len + 2,
-                width_name = max_name_len + 2
+                width_name = max_name_len + 2,
+                width_type = max_type_len + 2,
+                width_ro = ro_len + 2
             );
         }
         OutputMode::Normal => {
             for disk in disks {
+                let frac = disk.display_frac(inodes_mode);
                 println!(
-                    "{} @ {}\n{} {:.0}%\n",
+                    "{}{} @ {}\n{} {:.0}%\n",
                     disk.name,
+                    disk.ro_marker(),
                     disk.mnt,
-                    disk.create_bar(),
-                    disk.space_as_frac * 100.0
+                    disk.create_bar(frac),
+                    frac * 100.0
                 );
             }
         }